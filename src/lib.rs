@@ -1,4 +1,7 @@
 #![deny(clippy::all)]
+use std::collections::HashSet;
+use std::time::Duration;
+
 use napi::bindgen_prelude::Uint16Array;
 use napi_derive::napi;
 
@@ -14,9 +17,20 @@ impl Default for EnigoJs {
   }
 }
 
+/// A key or mouse button that `EnigoJs` tracks as logically held down.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum PressedInput {
+  Key(KeyboardKey),
+  Button(MouseButton),
+}
+
 #[napi(js_name = "Enigo")]
 pub struct EnigoJs {
   enigo: Enigo,
+  // Tracks keys/buttons this instance has pressed but not yet released, in
+  // press order, so `release_all` can undo a stuck sequence deterministically.
+  pressed: Vec<PressedInput>,
+  pressed_set: HashSet<PressedInput>,
 }
 
 #[napi]
@@ -25,6 +39,42 @@ impl EnigoJs {
   pub fn new() -> Self {
     EnigoJs {
       enigo: Enigo::new(&Settings::default()).unwrap(),
+      pressed: Vec::new(),
+      pressed_set: HashSet::new(),
+    }
+  }
+
+  /// Creates an `Enigo` instance with `enigo::Settings` tuned via `options`,
+  /// instead of being locked to `Settings::default()`.
+  #[napi(factory)]
+  pub fn with_options(options: EnigoOptions) -> Result<Self, napi::Error> {
+    let mut settings = Settings::default();
+    if let Some(release_keys_when_dropped) = options.release_keys_when_dropped {
+      settings.release_keys_when_dropped = release_keys_when_dropped;
+    }
+    if let Some(mac_delay) = options.mac_delay {
+      settings.mac_delay = mac_delay;
+    }
+    if let Some(linux_delay) = options.linux_delay {
+      settings.linux_delay = linux_delay;
+    }
+
+    Ok(EnigoJs {
+      enigo: Enigo::new(&settings).map_err(|e| napi::Error::from_reason(e.to_string()))?,
+      pressed: Vec::new(),
+      pressed_set: HashSet::new(),
+    })
+  }
+
+  fn track_press(&mut self, input: PressedInput) {
+    if self.pressed_set.insert(input) {
+      self.pressed.push(input);
+    }
+  }
+
+  fn track_release(&mut self, input: PressedInput) {
+    if self.pressed_set.remove(&input) {
+      self.pressed.retain(|tracked| *tracked != input);
     }
   }
 
@@ -63,31 +113,89 @@ impl EnigoJs {
       .map_err(|e| napi::Error::from_reason(e.to_string()))
   }
 
+  /// Moves the mouse by `(dx, dy)` relative to its current position, for
+  /// smooth drags or game input where the absolute position doesn't matter.
+  #[napi]
+  pub fn move_mouse_relative(&mut self, dx: i32, dy: i32) -> Result<(), napi::Error> {
+    self
+      .enigo
+      .move_mouse(dx, dy, Coordinate::Rel)
+      .map_err(|e| napi::Error::from_reason(e.to_string()))
+  }
+
   #[napi]
   pub fn set_button_click(&mut self, button: MouseButton) -> Result<(), napi::Error> {
-    let button = match button {
-      MouseButton::Left => Button::Left,
-      MouseButton::Middle => Button::Middle,
-      MouseButton::Right => Button::Right,
-    };
     self
       .enigo
-      .button(button, Click)
+      .button(transform_button(button), Click)
       .map_err(|e| napi::Error::from_reason(e.to_string()))
   }
 
   #[napi]
   pub fn set_button_toggle(&mut self, button: MouseButton, down: bool) -> Result<(), napi::Error> {
-    let button = match button {
-      MouseButton::Left => Button::Left,
-      MouseButton::Middle => Button::Middle,
-      MouseButton::Right => Button::Right,
-    };
     let direction = if down { Press } else { Release };
     self
       .enigo
-      .button(button, direction)
-      .map_err(|e| napi::Error::from_reason(e.to_string()))
+      .button(transform_button(button), direction)
+      .map_err(|e| napi::Error::from_reason(e.to_string()))?;
+    if down {
+      self.track_press(PressedInput::Button(button));
+    } else {
+      self.track_release(PressedInput::Button(button));
+    }
+    Ok(())
+  }
+
+  /// Issues `count` consecutive clicks of `button`, sleeping `interval_ms`
+  /// (default ~50ms) between each. The OS only recognizes two clicks as a
+  /// double-click when they land within its own double-click interval, so
+  /// this lets callers produce a true double/triple-click instead of two
+  /// independent single clicks.
+  ///
+  /// Each sleep blocks the Node.js event loop, so `count` and `interval_ms`
+  /// are capped (`MAX_MULTI_CLICK_COUNT`, `MAX_MULTI_CLICK_INTERVAL_MS`) to
+  /// keep a runaway or attacker-controlled value from stalling the process.
+  #[napi]
+  pub fn set_button_multi_click(
+    &mut self,
+    button: MouseButton,
+    count: u32,
+    interval_ms: Option<u32>,
+  ) -> Result<(), napi::Error> {
+    if count > MAX_MULTI_CLICK_COUNT {
+      return Err(napi::Error::from_reason(format!(
+        "count must be at most {MAX_MULTI_CLICK_COUNT}"
+      )));
+    }
+    let interval_ms = interval_ms.unwrap_or(50);
+    if interval_ms > MAX_MULTI_CLICK_INTERVAL_MS {
+      return Err(napi::Error::from_reason(format!(
+        "interval_ms must be at most {MAX_MULTI_CLICK_INTERVAL_MS}"
+      )));
+    }
+
+    let button = transform_button(button);
+    let interval = Duration::from_millis(interval_ms as u64);
+
+    for i in 0..count {
+      if i > 0 {
+        std::thread::sleep(interval);
+      }
+      self
+        .enigo
+        .button(button, Click)
+        .map_err(|e| napi::Error::from_reason(e.to_string()))?;
+    }
+    Ok(())
+  }
+
+  #[napi]
+  pub fn set_button_double_click(
+    &mut self,
+    button: MouseButton,
+    interval_ms: Option<u32>,
+  ) -> Result<(), napi::Error> {
+    self.set_button_multi_click(button, 2, interval_ms)
   }
 
   #[napi]
@@ -96,13 +204,30 @@ impl EnigoJs {
     direction: ScrollDirection,
     clicks: i32,
   ) -> Result<(), napi::Error> {
-    let length = match direction {
-      ScrollDirection::Down => clicks,
-      ScrollDirection::Up => -clicks,
+    let (length, axis) = match direction {
+      ScrollDirection::Down => (clicks, Axis::Vertical),
+      ScrollDirection::Up => (-clicks, Axis::Vertical),
+      ScrollDirection::Right => (clicks, Axis::Horizontal),
+      ScrollDirection::Left => (-clicks, Axis::Horizontal),
     };
     self
       .enigo
-      .scroll(length, Axis::Vertical) // Fix: Use Axis enum
+      .scroll(length, axis)
+      .map_err(|e| napi::Error::from_reason(e.to_string()))
+  }
+
+  /// Scrolls `amount` along an explicit axis. Unlike [`EnigoJs::set_mouse_scroll`],
+  /// the sign of `amount` is passed straight through to `enigo` rather than
+  /// being flipped per direction.
+  #[napi]
+  pub fn scroll(&mut self, axis: ScrollAxis, amount: i32) -> Result<(), napi::Error> {
+    let axis = match axis {
+      ScrollAxis::Horizontal => Axis::Horizontal,
+      ScrollAxis::Vertical => Axis::Vertical,
+    };
+    self
+      .enigo
+      .scroll(amount, axis)
       .map_err(|e| napi::Error::from_reason(e.to_string()))
   }
 
@@ -120,11 +245,12 @@ impl EnigoJs {
   #[napi]
   pub fn press_key(&mut self, keys: Vec<KeyboardKey>) -> Result<(), napi::Error> {
     for key in keys {
-      let key = transform_key(key); // Fix: use snake_case for function name
+      let transformed = transform_key(key); // Fix: use snake_case for function name
       self
         .enigo
-        .key(key, Press)
+        .key(transformed, Press)
         .map_err(|e| napi::Error::from_reason(e.to_string()))?;
+      self.track_press(PressedInput::Key(key));
     }
     Ok(())
   }
@@ -133,11 +259,12 @@ impl EnigoJs {
   #[napi]
   pub fn release_key(&mut self, keys: Vec<KeyboardKey>) -> Result<(), napi::Error> {
     for key in keys {
-      let key = transform_key(key); // Fix: use snake_case for function name
+      let transformed = transform_key(key); // Fix: use snake_case for function name
       self
         .enigo
-        .key(key, Release)
+        .key(transformed, Release)
         .map_err(|e| napi::Error::from_reason(e.to_string()))?;
+      self.track_release(PressedInput::Key(key));
     }
     Ok(())
   }
@@ -164,6 +291,128 @@ impl EnigoJs {
     }
     Ok(())
   }
+
+  /// Presses `modifiers` in order, then `key`, then releases `key` and the
+  /// modifiers in reverse order. If any step fails, every modifier already
+  /// pressed is released before the error is returned. Releases are
+  /// best-effort: a modifier whose own release fails stays pressed and stays
+  /// tracked, so `release_all` can retry it.
+  #[napi]
+  pub fn send_hotkey(
+    &mut self,
+    modifiers: Vec<KeyboardKey>,
+    key: KeyboardKey,
+  ) -> Result<(), napi::Error> {
+    let mut pressed: Vec<KeyboardKey> = Vec::new();
+
+    for modifier in &modifiers {
+      if let Err(e) = self.enigo.key(transform_key(*modifier), Press) {
+        self.release_keys(&pressed);
+        return Err(napi::Error::from_reason(e.to_string()));
+      }
+      self.track_press(PressedInput::Key(*modifier));
+      pressed.push(*modifier);
+    }
+
+    if let Err(e) = self.enigo.key(transform_key(key), Press) {
+      self.release_keys(&pressed);
+      return Err(napi::Error::from_reason(e.to_string()));
+    }
+    self.track_press(PressedInput::Key(key));
+
+    if let Err(e) = self.enigo.key(transform_key(key), Release) {
+      self.release_keys(&pressed);
+      return Err(napi::Error::from_reason(e.to_string()));
+    }
+    self.track_release(PressedInput::Key(key));
+
+    match self.release_keys(&pressed) {
+      Some(e) => Err(e),
+      None => Ok(()),
+    }
+  }
+
+  /// Convenience form of [`EnigoJs::send_hotkey`] that parses a string like
+  /// `"ctrl+shift+k"`. Tokens are matched case-insensitively; `ctrl`/`control`,
+  /// `shift`, `alt`, and `meta`/`cmd`/`super` are treated as modifiers and the
+  /// final token is the main key.
+  #[napi]
+  pub fn send_hotkey_str(&mut self, hotkey: String) -> Result<(), napi::Error> {
+    if hotkey.trim().is_empty() {
+      return Err(napi::Error::from_reason("hotkey string must not be empty".to_string()));
+    }
+    let tokens: Vec<&str> = hotkey.split('+').map(str::trim).collect();
+    let (key_token, modifier_tokens) = tokens
+      .split_last()
+      .ok_or_else(|| napi::Error::from_reason("hotkey string must not be empty".to_string()))?;
+
+    let modifiers = modifier_tokens
+      .iter()
+      .map(|token| parse_modifier_key(token))
+      .collect::<Result<Vec<KeyboardKey>, napi::Error>>()?;
+    let key = parse_keyboard_key(key_token)?;
+
+    self.send_hotkey(modifiers, key)
+  }
+
+  /// Releases `keys` in reverse order on a best-effort basis, attempting
+  /// every release even if an earlier one fails, and returns the first
+  /// error encountered (if any) so the caller can decide whether to
+  /// propagate it.
+  fn release_keys(&mut self, keys: &[KeyboardKey]) -> Option<napi::Error> {
+    let mut first_error = None;
+    for key in keys.iter().rev() {
+      match self.enigo.key(transform_key(*key), Release) {
+        Ok(()) => self.track_release(PressedInput::Key(*key)),
+        Err(e) => {
+          first_error.get_or_insert_with(|| napi::Error::from_reason(e.to_string()));
+        }
+      }
+    }
+    first_error
+  }
+
+  /// Returns every `KeyboardKey` this instance currently believes is pressed,
+  /// in the order it was pressed. Independent of the platform-level `held()`
+  /// query, which reflects actual OS keyboard state rather than what this
+  /// instance has pressed.
+  #[napi]
+  pub fn pressed_keys(&self) -> Vec<KeyboardKey> {
+    self
+      .pressed
+      .iter()
+      .filter_map(|input| match input {
+        PressedInput::Key(key) => Some(*key),
+        PressedInput::Button(_) => None,
+      })
+      .collect()
+  }
+
+  /// Releases every key and mouse button this instance has pressed but not
+  /// yet released, in reverse press order, then clears the tracked state.
+  /// Use this to guarantee a clean keyboard/mouse state after a macro that
+  /// may have failed partway through.
+  #[napi]
+  pub fn release_all(&mut self) -> Result<(), napi::Error> {
+    let pressed = std::mem::take(&mut self.pressed);
+    self.pressed_set.clear();
+
+    let mut first_error = None;
+    for input in pressed.into_iter().rev() {
+      let result = match input {
+        PressedInput::Key(key) => self.enigo.key(transform_key(key), Release),
+        PressedInput::Button(button) => self.enigo.button(transform_button(button), Release),
+      };
+      if let Err(e) = result {
+        first_error.get_or_insert_with(|| napi::Error::from_reason(e.to_string()));
+      }
+    }
+
+    match first_error {
+      Some(e) => Err(e),
+      None => Ok(()),
+    }
+  }
 }
 
 // Get Active Window
@@ -178,9 +427,18 @@ impl EnigoJs {
 pub enum ScrollDirection {
   Down = 0,
   Up = 1,
+  Left = 2,
+  Right = 3,
+}
+
+#[napi]
+pub enum ScrollAxis {
+  Horizontal = 0,
+  Vertical = 1,
 }
 
 #[napi]
+#[derive(PartialEq, Eq, Hash)]
 pub enum MouseButton {
   Left = 0,
   Middle = 1,
@@ -188,6 +446,7 @@ pub enum MouseButton {
 }
 
 #[napi]
+#[derive(PartialEq, Eq, Hash)]
 pub enum KeyboardKey {
   Num0 = 0,
   Num1 = 1,
@@ -244,6 +503,65 @@ pub enum KeyboardKey {
   LeftArrow = 52,
   RightArrow = 53,
   Meta = 54,
+  F1 = 55,
+  F2 = 56,
+  F3 = 57,
+  F4 = 58,
+  F5 = 59,
+  F6 = 60,
+  F7 = 61,
+  F8 = 62,
+  F9 = 63,
+  F10 = 64,
+  F11 = 65,
+  F12 = 66,
+  F13 = 67,
+  F14 = 68,
+  F15 = 69,
+  F16 = 70,
+  F17 = 71,
+  F18 = 72,
+  F19 = 73,
+  F20 = 74,
+  F21 = 75,
+  F22 = 76,
+  F23 = 77,
+  F24 = 78,
+  Home = 79,
+  End = 80,
+  Insert = 81,
+  Delete = 82,
+  PageUp = 83,
+  PageDown = 84,
+  PrintScreen = 85,
+  Pause = 86,
+  NumLock = 87,
+  ScrollLock = 88,
+  Numpad0 = 89,
+  Numpad1 = 90,
+  Numpad2 = 91,
+  Numpad3 = 92,
+  Numpad4 = 93,
+  Numpad5 = 94,
+  Numpad6 = 95,
+  Numpad7 = 96,
+  Numpad8 = 97,
+  Numpad9 = 98,
+  NumpadAdd = 99,
+  NumpadSubtract = 100,
+  NumpadMultiply = 101,
+  NumpadDivide = 102,
+  NumpadDecimal = 103,
+  NumpadEnter = 104,
+  OEM1 = 105,
+  OEMPlus = 106,
+  OEMComma = 107,
+  OEMPeriod = 108,
+  OEM3 = 109,
+  OEM4 = 110,
+  OEM5 = 111,
+  OEM6 = 112,
+  OEM7 = 113,
 }
 
 // Fix: Use snake_case for function name
@@ -303,10 +621,210 @@ fn transform_key(key: KeyboardKey) -> Key {
     KeyboardKey::DownArrow => Key::DownArrow,
     KeyboardKey::LeftArrow => Key::LeftArrow,
     KeyboardKey::RightArrow => Key::RightArrow,
-    KeyboardKey::OEM2 => Key::End, // Use a placeholder or default key
+    KeyboardKey::F1 => Key::F1,
+    KeyboardKey::F2 => Key::F2,
+    KeyboardKey::F3 => Key::F3,
+    KeyboardKey::F4 => Key::F4,
+    KeyboardKey::F5 => Key::F5,
+    KeyboardKey::F6 => Key::F6,
+    KeyboardKey::F7 => Key::F7,
+    KeyboardKey::F8 => Key::F8,
+    KeyboardKey::F9 => Key::F9,
+    KeyboardKey::F10 => Key::F10,
+    KeyboardKey::F11 => Key::F11,
+    KeyboardKey::F12 => Key::F12,
+    KeyboardKey::F13 => Key::F13,
+    KeyboardKey::F14 => Key::F14,
+    KeyboardKey::F15 => Key::F15,
+    KeyboardKey::F16 => Key::F16,
+    KeyboardKey::F17 => Key::F17,
+    KeyboardKey::F18 => Key::F18,
+    KeyboardKey::F19 => Key::F19,
+    KeyboardKey::F20 => Key::F20,
+    KeyboardKey::F21 => Key::F21,
+    KeyboardKey::F22 => Key::F22,
+    KeyboardKey::F23 => Key::F23,
+    KeyboardKey::F24 => Key::F24,
+    KeyboardKey::Home => Key::Home,
+    KeyboardKey::End => Key::End,
+    KeyboardKey::Insert => Key::Insert,
+    KeyboardKey::Delete => Key::Delete,
+    KeyboardKey::PageUp => Key::PageUp,
+    KeyboardKey::PageDown => Key::PageDown,
+    KeyboardKey::PrintScreen => Key::Print,
+    KeyboardKey::Pause => Key::Pause,
+    KeyboardKey::NumLock => Key::Numlock,
+    KeyboardKey::ScrollLock => Key::ScrollLock,
+    KeyboardKey::Numpad0 => Key::Unicode('0'),
+    KeyboardKey::Numpad1 => Key::Unicode('1'),
+    KeyboardKey::Numpad2 => Key::Unicode('2'),
+    KeyboardKey::Numpad3 => Key::Unicode('3'),
+    KeyboardKey::Numpad4 => Key::Unicode('4'),
+    KeyboardKey::Numpad5 => Key::Unicode('5'),
+    KeyboardKey::Numpad6 => Key::Unicode('6'),
+    KeyboardKey::Numpad7 => Key::Unicode('7'),
+    KeyboardKey::Numpad8 => Key::Unicode('8'),
+    KeyboardKey::Numpad9 => Key::Unicode('9'),
+    KeyboardKey::NumpadAdd => Key::Unicode('+'),
+    KeyboardKey::NumpadSubtract => Key::Unicode('-'),
+    KeyboardKey::NumpadMultiply => Key::Unicode('*'),
+    KeyboardKey::NumpadDivide => Key::Unicode('/'),
+    KeyboardKey::NumpadDecimal => Key::Unicode('.'),
+    KeyboardKey::NumpadEnter => Key::Return,
+    KeyboardKey::OEM1 => Key::Unicode(';'),
+    KeyboardKey::OEMPlus => Key::Unicode('='),
+    KeyboardKey::OEMComma => Key::Unicode(','),
+    KeyboardKey::OEMPeriod => Key::Unicode('.'),
+    KeyboardKey::OEM2 => Key::Unicode('/'),
+    KeyboardKey::OEM3 => Key::Unicode('`'),
+    KeyboardKey::OEM4 => Key::Unicode('['),
+    KeyboardKey::OEM5 => Key::Unicode('\\'),
+    KeyboardKey::OEM6 => Key::Unicode(']'),
+    KeyboardKey::OEM7 => Key::Unicode('\''),
   }
 }
 
+/// Upper bound on `set_button_multi_click`'s `count`, since each click after
+/// the first blocks the Node.js event loop for `interval_ms`.
+const MAX_MULTI_CLICK_COUNT: u32 = 50;
+/// Upper bound on `set_button_multi_click`'s `interval_ms`, for the same reason.
+const MAX_MULTI_CLICK_INTERVAL_MS: u32 = 2_000;
+
+fn transform_button(button: MouseButton) -> Button {
+  match button {
+    MouseButton::Left => Button::Left,
+    MouseButton::Middle => Button::Middle,
+    MouseButton::Right => Button::Right,
+  }
+}
+
+fn parse_modifier_key(token: &str) -> Result<KeyboardKey, napi::Error> {
+  match token.to_lowercase().as_str() {
+    "ctrl" | "control" => Ok(KeyboardKey::Control),
+    "shift" => Ok(KeyboardKey::Shift),
+    "alt" => Ok(KeyboardKey::Alt),
+    "meta" | "cmd" | "super" => Ok(KeyboardKey::Meta),
+    other => Err(napi::Error::from_reason(format!(
+      "unknown modifier key: {other}"
+    ))),
+  }
+}
+
+fn parse_keyboard_key(token: &str) -> Result<KeyboardKey, napi::Error> {
+  let key = match token.to_lowercase().as_str() {
+    "0" => KeyboardKey::Num0,
+    "1" => KeyboardKey::Num1,
+    "2" => KeyboardKey::Num2,
+    "3" => KeyboardKey::Num3,
+    "4" => KeyboardKey::Num4,
+    "5" => KeyboardKey::Num5,
+    "6" => KeyboardKey::Num6,
+    "7" => KeyboardKey::Num7,
+    "8" => KeyboardKey::Num8,
+    "9" => KeyboardKey::Num9,
+    "a" => KeyboardKey::A,
+    "b" => KeyboardKey::B,
+    "c" => KeyboardKey::C,
+    "d" => KeyboardKey::D,
+    "e" => KeyboardKey::E,
+    "f" => KeyboardKey::F,
+    "g" => KeyboardKey::G,
+    "h" => KeyboardKey::H,
+    "i" => KeyboardKey::I,
+    "j" => KeyboardKey::J,
+    "k" => KeyboardKey::K,
+    "l" => KeyboardKey::L,
+    "m" => KeyboardKey::M,
+    "n" => KeyboardKey::N,
+    "o" => KeyboardKey::O,
+    "p" => KeyboardKey::P,
+    "q" => KeyboardKey::Q,
+    "r" => KeyboardKey::R,
+    "s" => KeyboardKey::S,
+    "t" => KeyboardKey::T,
+    "u" => KeyboardKey::U,
+    "v" => KeyboardKey::V,
+    "w" => KeyboardKey::W,
+    "x" => KeyboardKey::X,
+    "y" => KeyboardKey::Y,
+    "z" => KeyboardKey::Z,
+    "tab" => KeyboardKey::Tab,
+    "capslock" => KeyboardKey::CapsLock,
+    "space" => KeyboardKey::Space,
+    "backspace" => KeyboardKey::Backspace,
+    "enter" | "return" => KeyboardKey::Return,
+    "escape" | "esc" => KeyboardKey::Escape,
+    "up" => KeyboardKey::UpArrow,
+    "down" => KeyboardKey::DownArrow,
+    "left" => KeyboardKey::LeftArrow,
+    "right" => KeyboardKey::RightArrow,
+    "home" => KeyboardKey::Home,
+    "end" => KeyboardKey::End,
+    "insert" => KeyboardKey::Insert,
+    "delete" | "del" => KeyboardKey::Delete,
+    "pageup" => KeyboardKey::PageUp,
+    "pagedown" => KeyboardKey::PageDown,
+    "printscreen" => KeyboardKey::PrintScreen,
+    "pause" => KeyboardKey::Pause,
+    "numlock" => KeyboardKey::NumLock,
+    "scrolllock" => KeyboardKey::ScrollLock,
+    "numpad0" => KeyboardKey::Numpad0,
+    "numpad1" => KeyboardKey::Numpad1,
+    "numpad2" => KeyboardKey::Numpad2,
+    "numpad3" => KeyboardKey::Numpad3,
+    "numpad4" => KeyboardKey::Numpad4,
+    "numpad5" => KeyboardKey::Numpad5,
+    "numpad6" => KeyboardKey::Numpad6,
+    "numpad7" => KeyboardKey::Numpad7,
+    "numpad8" => KeyboardKey::Numpad8,
+    "numpad9" => KeyboardKey::Numpad9,
+    "numpadadd" => KeyboardKey::NumpadAdd,
+    "numpadsubtract" => KeyboardKey::NumpadSubtract,
+    "numpadmultiply" => KeyboardKey::NumpadMultiply,
+    "numpaddivide" => KeyboardKey::NumpadDivide,
+    "numpaddecimal" => KeyboardKey::NumpadDecimal,
+    "numpadenter" => KeyboardKey::NumpadEnter,
+    ";" | "semicolon" => KeyboardKey::OEM1,
+    "=" | "equal" => KeyboardKey::OEMPlus,
+    "," | "comma" => KeyboardKey::OEMComma,
+    "." | "period" => KeyboardKey::OEMPeriod,
+    "/" | "slash" => KeyboardKey::OEM2,
+    "`" | "grave" => KeyboardKey::OEM3,
+    "[" | "bracketleft" => KeyboardKey::OEM4,
+    "\\" | "backslash" => KeyboardKey::OEM5,
+    "]" | "bracketright" => KeyboardKey::OEM6,
+    "'" | "quote" => KeyboardKey::OEM7,
+    "f1" => KeyboardKey::F1,
+    "f2" => KeyboardKey::F2,
+    "f3" => KeyboardKey::F3,
+    "f4" => KeyboardKey::F4,
+    "f5" => KeyboardKey::F5,
+    "f6" => KeyboardKey::F6,
+    "f7" => KeyboardKey::F7,
+    "f8" => KeyboardKey::F8,
+    "f9" => KeyboardKey::F9,
+    "f10" => KeyboardKey::F10,
+    "f11" => KeyboardKey::F11,
+    "f12" => KeyboardKey::F12,
+    "f13" => KeyboardKey::F13,
+    "f14" => KeyboardKey::F14,
+    "f15" => KeyboardKey::F15,
+    "f16" => KeyboardKey::F16,
+    "f17" => KeyboardKey::F17,
+    "f18" => KeyboardKey::F18,
+    "f19" => KeyboardKey::F19,
+    "f20" => KeyboardKey::F20,
+    "f21" => KeyboardKey::F21,
+    "f22" => KeyboardKey::F22,
+    "f23" => KeyboardKey::F23,
+    "f24" => KeyboardKey::F24,
+    other => {
+      return Err(napi::Error::from_reason(format!("unknown key: {other}")));
+    }
+  };
+  Ok(key)
+}
+
 #[napi(object)]
 pub struct Point {
   pub x: i32,
@@ -324,3 +842,12 @@ pub struct ToggleKey {
   pub value: KeyboardKey,
   pub down: bool,
 }
+
+/// Optional overrides for `enigo::Settings`, for `EnigoJs::with_options`.
+/// Any field left `None` falls back to `Settings::default()`.
+#[napi(object)]
+pub struct EnigoOptions {
+  pub release_keys_when_dropped: Option<bool>,
+  pub mac_delay: Option<u32>,
+  pub linux_delay: Option<u32>,
+}